@@ -21,6 +21,23 @@ pub enum MinMaxValue {
     None,
 }
 
+/// Quantiles computed over a numeric column via sorted quantile interpolation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quantiles {
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+/// A fixed-width histogram: `bin_edges` has `counts.len() + 1` entries, so bin `i`
+/// spans `[bin_edges[i], bin_edges[i + 1])` (the last bin is closed on both ends).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Histogram {
+    pub bin_edges: Vec<f64>,
+    pub counts: Vec<usize>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColumnProfileDetailed {
     pub column: String,
@@ -30,8 +47,16 @@ pub struct ColumnProfileDetailed {
     pub min: MinMaxValue,
     pub max: MinMaxValue,
     pub sample_values: Option<Vec<String>>,
+    pub quantiles: Option<Quantiles>,
+    pub histogram: Option<Histogram>,
+    pub top_values: Vec<(String, usize)>,
 }
 
+/// Default number of equal-width bins in a numeric column's histogram.
+pub const DEFAULT_HISTOGRAM_BINS: usize = 10;
+/// Default number of most-frequent values kept in `top_values`.
+pub const DEFAULT_TOP_K: usize = 10;
+
 pub fn profile_df(df: DataFrame) -> Result<(usize, Vec<Profile>)> {
     let row_count = df.height();
     let profiles = df
@@ -46,7 +71,16 @@ pub fn profile_df(df: DataFrame) -> Result<(usize, Vec<Profile>)> {
     Ok((row_count, profiles))
 }
 
-pub fn profile_df_detailed(df: &DataFrame) -> Result<(usize, Vec<ColumnProfileDetailed>)> {
+/// Profiles a DataFrame in detail, with numeric quantiles/histograms and a
+/// top-k frequency table alongside the existing dtype/nulls/unique/min/max/
+/// sample-values fields. `bin_count` controls the histogram's number of
+/// equal-width bins (Int/Float columns only); `top_k` controls how many of the
+/// most frequent values are kept per String/Boolean column.
+pub fn profile_df_detailed(
+    df: &DataFrame,
+    bin_count: usize,
+    top_k: usize,
+) -> Result<(usize, Vec<ColumnProfileDetailed>)> {
     let row_count = df.height();
     let profiles: Vec<ColumnProfileDetailed> = df
         .get_columns()
@@ -132,6 +166,20 @@ pub fn profile_df_detailed(df: &DataFrame) -> Result<(usize, Vec<ColumnProfileDe
             } else {
                 None
             };
+
+            let is_numeric = matches!(
+                dtype_obj,
+                DataType::Int64 | DataType::Int32 | DataType::Float64 | DataType::Float32
+            );
+            let quantiles = is_numeric.then(|| quantiles_for(s)).flatten();
+            let histogram = is_numeric.then(|| histogram_for(s, bin_count)).flatten();
+
+            let top_values = if matches!(dtype_obj, DataType::String | DataType::Boolean) {
+                top_k_values(s, top_k).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+
             ColumnProfileDetailed {
                 column: s.name().to_string(),
                 dtype,
@@ -140,15 +188,123 @@ pub fn profile_df_detailed(df: &DataFrame) -> Result<(usize, Vec<ColumnProfileDe
                 min,
                 max,
                 sample_values,
+                quantiles,
+                histogram,
+                top_values,
             }
         })
         .collect();
     Ok((row_count, profiles))
 }
 
+/// Computes p25/p50/p75/p95 over a numeric column via Polars' linear-interpolated
+/// quantile, returning `f64::NAN` for any percentile that can't be resolved (e.g.
+/// an all-null column) rather than failing the whole profile.
+fn quantiles_for(s: &Series) -> Option<Quantiles> {
+    let p = |q: f64| {
+        s.quantile_reduce(q, QuantileMethod::Linear)
+            .ok()
+            .and_then(|scalar| scalar.value().extract::<f64>())
+            .unwrap_or(f64::NAN)
+    };
+    Some(Quantiles {
+        p25: p(0.25),
+        p50: p(0.5),
+        p75: p(0.75),
+        p95: p(0.95),
+    })
+}
+
+/// Buckets a numeric column's non-null values into `bin_count` equal-width bins
+/// spanning `[min, max]`. Returns `None` for an empty/all-null column or a
+/// degenerate (non-finite) min/max.
+fn histogram_for(s: &Series, bin_count: usize) -> Option<Histogram> {
+    if bin_count == 0 {
+        return None;
+    }
+    let values: Vec<f64> = s.cast(&DataType::Float64).ok()?.f64().ok()?.into_iter().flatten().collect();
+    if values.is_empty() {
+        return None;
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !min.is_finite() || !max.is_finite() {
+        return None;
+    }
+
+    let width = if max > min { (max - min) / bin_count as f64 } else { 0.0 };
+    let mut counts = vec![0usize; bin_count];
+    for v in &values {
+        let idx = if width == 0.0 {
+            0
+        } else {
+            (((v - min) / width) as usize).min(bin_count - 1)
+        };
+        counts[idx] += 1;
+    }
+    let bin_edges = (0..=bin_count).map(|i| min + width * i as f64).collect();
+    Some(Histogram { bin_edges, counts })
+}
+
+/// Reports the `k` most frequent values in `s` and their counts, via a lazy
+/// group/count/sort/limit query so it rides along the same engine as the rest
+/// of the profile rather than a separate hand-rolled counting pass.
+fn top_k_values(s: &Series, k: usize) -> Result<Vec<(String, usize)>> {
+    let name = s.name().clone();
+    let df = DataFrame::new(vec![s.clone().into_column()])?;
+    let counted = df
+        .lazy()
+        .group_by([col(name.clone())])
+        .agg([len().alias("__count")])
+        .sort(["__count"], SortMultipleOptions::default().with_order_descending(true))
+        .limit(k as IdxSize)
+        .collect()?;
+
+    let values = counted.column(name.as_str())?;
+    let counts = counted.column("__count")?;
+    Ok((0..counted.height())
+        .filter_map(|i| {
+            let value = values.get(i).ok()?;
+            if matches!(value, AnyValue::Null) {
+                return None;
+            }
+            let count = counts.get(i).ok()?.extract::<usize>()?;
+            Some((format!("{value}"), count))
+        })
+        .collect())
+}
+
 pub fn export_profile_to_json(profiles: &[ColumnProfileDetailed], path: &Path) -> Result<()> {
     let json = serde_json::to_string_pretty(profiles)?;
     let mut file = File::create(path)?;
     file.write_all(json.as_bytes())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_df_detailed_histogram_and_top_values() {
+        let df = df! {
+            "score" => &[1_i64, 2, 2, 3, 10],
+            "label" => &["a", "a", "b", "a", "c"],
+        }
+        .unwrap();
+
+        let (row_count, profiles) = profile_df_detailed(&df, 3, 2).unwrap();
+        assert_eq!(row_count, 5);
+
+        let score = profiles.iter().find(|p| p.column == "score").unwrap();
+        let histogram = score.histogram.as_ref().expect("numeric column should have a histogram");
+        assert_eq!(histogram.counts.len(), 3);
+        assert_eq!(histogram.counts.iter().sum::<usize>(), 5);
+        assert!(score.quantiles.is_some());
+
+        let label = profiles.iter().find(|p| p.column == "label").unwrap();
+        assert!(label.histogram.is_none());
+        assert_eq!(label.top_values.first().map(|(v, c)| (v.as_str(), *c)), Some(("a", 3)));
+    }
+}
@@ -12,11 +12,14 @@ pub struct Cli {
 
     #[clap(flatten)]
     pub output: OutputArgs,
+
+    #[arg(long, help = "SQLite database file backing --format sql / --out-format sql")]
+    pub db: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
 pub struct InputArgs {
-    #[arg(short, long, help = "Path to the input file")]
+    #[arg(short, long, help = "Path to the input file, or a SELECT query when --format sql")]
     pub file: PathBuf,
 
     #[arg(long, default_value = "csv", value_enum, help = "Input file format")]
@@ -33,11 +36,20 @@ pub struct TransformArgs {
 
     #[arg(long, help = "Value to filter for (optional)")]
     pub filter_val: Option<String>,
+
+    #[arg(long, help = "Run the pipeline lazily with streaming scans/sinks instead of collecting eagerly")]
+    pub streaming: bool,
+
+    #[arg(long, help = "Run an SQL query over the input (and any --join-file tables) via a Polars SQLContext instead of drop-nulls/filter")]
+    pub sql: Option<String>,
+
+    #[arg(long, value_delimiter = ',', help = "Additional file(s) to join against in --sql, registered as tables named after their file stem")]
+    pub join_file: Vec<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
 pub struct OutputArgs {
-    #[arg(long, help = "Path to save the cleaned output (optional)")]
+    #[arg(long, help = "Path to save the cleaned output (optional), or a table name when --out-format sql")]
     pub output: Option<PathBuf>,
 
     #[arg(long, default_value = "csv", value_enum, help = "Output file format")]
@@ -49,4 +61,11 @@ pub enum FileFormat {
     Csv,
     Json,
     Parquet,
+    Sql,
+    /// Arrow IPC (Feather), via Polars' `IpcReader`/`IpcWriter`.
+    Arrow,
+    /// Via Polars' `AvroReader`/`AvroWriter`.
+    Avro,
+    /// Newline-delimited JSON, distinct from `Json`'s array shape.
+    Ndjson,
 }
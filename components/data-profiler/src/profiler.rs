@@ -6,7 +6,7 @@ use std::fs::File;
 use std::path::Path;
 use anyhow::{Result, Context};
 use polars::datatypes::DataType;
-use std::io::Write; 
+use std::io::Write;
 use serde::{Serialize, Deserialize};
 
 /// Supported input file formats
@@ -14,33 +14,130 @@ use serde::{Serialize, Deserialize};
 pub enum InputFormat {
     Csv,
     Json,
+    Ndjson,
     Parquet,
+    Arrow,
     Txt,
     Unknown,
 }
 
-/// Infer format from file extension
+/// Infer format from file extension.
+///
+/// `.json` files that turn out to hold a stream of newline-delimited top-level
+/// values — either because the first byte isn't `[`/`{` at all, or because a
+/// first top-level `{...}` object is followed by more content — are treated as
+/// NDJSON rather than rejected as malformed JSON.
 pub fn infer_format(path: &Path) -> InputFormat {
     match path.extension().and_then(|s| s.to_str()) {
         Some("csv") => InputFormat::Csv,
-        Some("json") => InputFormat::Json,
+        Some("ndjson") | Some("jsonl") => InputFormat::Ndjson,
+        Some("json") => {
+            if sniffs_as_ndjson(path) {
+                InputFormat::Ndjson
+            } else {
+                InputFormat::Json
+            }
+        }
         Some("parquet") => InputFormat::Parquet,
+        Some("arrow") | Some("ipc") | Some("feather") => InputFormat::Arrow,
         Some("txt") => InputFormat::Txt,
         _ => InputFormat::Unknown,
     }
 }
 
-pub fn read_dataframe(path: &Path, format: &InputFormat, delimiter: u8) -> Result<DataFrame> {
+/// Sniffs whether a `.json` file is a stream of newline-delimited top-level values
+/// rather than a single JSON value/array.
+///
+/// A leading `[` is always a JSON array, never NDJSON. A leading `{` is ambiguous —
+/// the common NDJSON shape (`{...}\n{...}`) also starts with `{` — so this scans
+/// past the first top-level object's matching `}` (respecting string quoting and
+/// escapes) and checks whether any non-whitespace content follows it. Anything
+/// else as the first byte (a bare number/string/bool stream) is always NDJSON.
+fn sniffs_as_ndjson(path: &Path) -> bool {
+    use std::io::Read;
+    let Ok(mut file) = File::open(path) else {
+        return false;
+    };
+    let mut buf = [0u8; 8192];
+    let Ok(n) = file.read(&mut buf) else {
+        return false;
+    };
+    let buf = &buf[..n];
+
+    let Some(start) = buf.iter().position(|b| !b.is_ascii_whitespace()) else {
+        return false;
+    };
+    match buf[start] {
+        b'[' => return false,
+        b'{' => {}
+        _ => return true,
+    }
+
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut end = None;
+    for (i, &b) in buf[start..].iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    end = Some(start + i + 1);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // If the object never closes within the buffer, assume a single (possibly
+    // large) JSON value rather than guessing NDJSON from incomplete information.
+    let Some(end) = end else {
+        return false;
+    };
+
+    buf[end..].iter().any(|b| !b.is_ascii_whitespace())
+}
+
+pub fn read_dataframe(path: &Path, format: &InputFormat, csv_opts: &CsvOptions) -> Result<DataFrame> {
     let file = File::open(path)
         .with_context(|| format!("Failed to open file: {}", path.display()))?;
 
     match format {
         InputFormat::Csv | InputFormat::Txt => {
-            let options = CsvReadOptions::default()
-                .with_has_header(true)
-                .map_parse_options(|opts| opts.with_separator(delimiter));
-            let reader = options.into_reader_with_file_handle(file);
-            reader
+            let mut options = CsvReadOptions::default()
+                .with_has_header(csv_opts.has_header)
+                .with_infer_schema_length(csv_opts.infer_schema_length)
+                .map_parse_options(|p| {
+                    let mut p = p
+                        .with_separator(csv_opts.separator)
+                        .with_quote_char(csv_opts.quote_char)
+                        .with_encoding(csv_opts.encoding);
+                    if !csv_opts.null_values.is_empty() {
+                        p = p.with_null_values(Some(NullValues::AllColumns(csv_opts.null_values.clone())));
+                    }
+                    if let Some(prefix) = &csv_opts.comment_prefix {
+                        p = p.with_comment_prefix(Some(prefix.into()));
+                    }
+                    p
+                });
+            if let Some(schema) = &csv_opts.schema {
+                options = options.with_schema(Some(schema.clone()));
+            }
+            options
+                .into_reader_with_file_handle(file)
                 .finish()
                 .context("Failed to read CSV/TXT file")
         }
@@ -49,15 +146,200 @@ pub fn read_dataframe(path: &Path, format: &InputFormat, delimiter: u8) -> Resul
                 .finish()
                 .context("Failed to read JSON file")
         }
+        InputFormat::Ndjson => read_ndjson_dataframe(path, 1 << 16, None),
         InputFormat::Parquet => {
             ParquetReader::new(file)
                 .finish()
                 .context("Failed to read Parquet file")
         }
+        InputFormat::Arrow => {
+            IpcReader::new(file)
+                .finish()
+                .context("Failed to read Arrow IPC file")
+        }
         InputFormat::Unknown => Err(anyhow::anyhow!("Unsupported file format")),
     }
 }
 
+/// Parse options for CSV/TXT inputs, mirroring Polars' `CsvParseOptions`/`CsvReadOptions`
+/// so real-world CSVs (sentinel nulls, comment headers, no header row, non-UTF8
+/// encoding) can be profiled correctly instead of assuming a plain comma-separated,
+/// UTF8, header-having file.
+#[derive(Clone)]
+pub struct CsvOptions {
+    pub has_header: bool,
+    pub separator: u8,
+    pub null_values: Vec<String>,
+    pub comment_prefix: Option<String>,
+    pub quote_char: Option<u8>,
+    pub encoding: CsvEncoding,
+    pub infer_schema_length: Option<usize>,
+    /// Explicit schema to use instead of inferring one from the data.
+    pub schema: Option<SchemaRef>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            separator: b',',
+            null_values: Vec::new(),
+            comment_prefix: None,
+            quote_char: Some(b'"'),
+            encoding: CsvEncoding::Utf8,
+            infer_schema_length: Some(100),
+            schema: None,
+        }
+    }
+}
+
+/// Reads a newline-delimited JSON (`.ndjson`/`.jsonl`) file.
+///
+/// `chunk_size` controls how many bytes `JsonLineReader` buffers per batch, and
+/// `n_rows` optionally caps how many records are read, which is useful for sampling
+/// a large file before committing to a full profile. The schema is inferred from
+/// every line (`infer_schema_len(None)`); no `AnyValue`-typed (object) fallback column
+/// is built for a field whose type differs across lines.
+pub fn read_ndjson_dataframe(
+    path: &Path,
+    chunk_size: usize,
+    n_rows: Option<usize>,
+) -> Result<DataFrame> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
+
+    let mut reader = JsonLineReader::new(file)
+        .with_chunk_size(Some(chunk_size))
+        .infer_schema_len(None);
+    if let Some(n_rows) = n_rows {
+        reader = reader.with_n_rows(Some(n_rows));
+    }
+    reader.finish().context("Failed to read NDJSON file")
+}
+
+/// Either an already-materialized `DataFrame` or a `LazyFrame` still to be evaluated.
+/// `write_dataframe` uses the lazy variant's streaming sinks when the target format
+/// supports one, instead of collecting first.
+pub enum FrameSource {
+    Eager(DataFrame),
+    Lazy(LazyFrame),
+}
+
+/// Per-format options for `write_dataframe`.
+#[derive(Clone)]
+pub struct WriteOptions {
+    pub csv_separator: u8,
+    pub csv_include_header: bool,
+    pub parquet_compression: Option<ParquetCompression>,
+    pub ipc_compression: Option<IpcCompression>,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            csv_separator: b',',
+            csv_include_header: true,
+            parquet_compression: None,
+            ipc_compression: None,
+        }
+    }
+}
+
+/// Writes a `DataFrame` or `LazyFrame` out as CSV, NDJSON, Parquet, or Arrow IPC,
+/// picking the format from the destination's extension via `infer_format`.
+///
+/// When `source` is a `LazyFrame` and the target format has a streaming sink
+/// (`sink_csv`/`sink_parquet`/`sink_ipc`), the write streams straight to disk during
+/// the collect instead of materializing a `DataFrame` first; NDJSON and already-eager
+/// frames fall back to collecting (if needed) plus the matching eager writer.
+pub fn write_dataframe(source: FrameSource, path: &Path, opts: &WriteOptions) -> Result<()> {
+    let format = infer_format(path);
+
+    match source {
+        FrameSource::Lazy(lf) => match format {
+            InputFormat::Csv | InputFormat::Txt => lf
+                .sink_csv(
+                    path,
+                    CsvWriterOptions {
+                        include_header: opts.csv_include_header,
+                        serialize_options: SerializeOptions {
+                            separator: opts.csv_separator,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    None,
+                    Default::default(),
+                )
+                .context("Failed to stream CSV/TXT file"),
+            InputFormat::Parquet => lf
+                .sink_parquet(
+                    path,
+                    ParquetWriteOptions {
+                        compression: opts.parquet_compression.unwrap_or_default(),
+                        ..Default::default()
+                    },
+                    None,
+                    Default::default(),
+                )
+                .context("Failed to stream Parquet file"),
+            InputFormat::Arrow => lf
+                .sink_ipc(
+                    path,
+                    IpcWriterOptions {
+                        compression: opts.ipc_compression,
+                        ..Default::default()
+                    },
+                    None,
+                    Default::default(),
+                )
+                .context("Failed to stream Arrow IPC file"),
+            InputFormat::Ndjson | InputFormat::Json | InputFormat::Unknown => {
+                let df = lf.collect().context("Failed to collect LazyFrame for writing")?;
+                write_dataframe(FrameSource::Eager(df), path, opts)
+            }
+        },
+        FrameSource::Eager(mut df) => {
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create file: {}", path.display()))?;
+            match format {
+                InputFormat::Csv | InputFormat::Txt => CsvWriter::new(file)
+                    .include_header(opts.csv_include_header)
+                    .with_separator(opts.csv_separator)
+                    .finish(&mut df)
+                    .context("Failed to write CSV/TXT file"),
+                InputFormat::Json => JsonWriter::new(file)
+                    .finish(&mut df)
+                    .context("Failed to write JSON file"),
+                InputFormat::Ndjson => JsonWriter::new(file)
+                    .with_json_format(JsonFormat::JsonLines)
+                    .finish(&mut df)
+                    .context("Failed to write NDJSON file"),
+                InputFormat::Parquet => {
+                    let mut writer = ParquetWriter::new(file);
+                    if let Some(compression) = opts.parquet_compression {
+                        writer = writer.with_compression(compression);
+                    }
+                    writer
+                        .finish(&mut df)
+                        .map(|_| ())
+                        .context("Failed to write Parquet file")
+                }
+                InputFormat::Arrow => {
+                    let mut writer = IpcWriter::new(file);
+                    if let Some(compression) = opts.ipc_compression {
+                        writer = writer.with_compression(Some(compression));
+                    }
+                    writer
+                        .finish(&mut df)
+                        .context("Failed to write Arrow IPC file")
+                }
+                InputFormat::Unknown => Err(anyhow::anyhow!("Unsupported output format")),
+            }
+        }
+    }
+}
+
 /// Profiling information for a single column
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Profile {
@@ -75,7 +357,33 @@ pub enum MinMaxValue {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-/// Detailed profiling information for a single column
+/// Distribution of a `List`-typed column's element lengths, reported instead of
+/// min/max/unique since those don't apply to the list itself.
+pub struct ListLengthStats {
+    pub element_dtype: String,
+    pub min_len: usize,
+    pub max_len: usize,
+    pub avg_len: f64,
+}
+
+/// Quantiles computed over a numeric column, at the percentiles `profile_leaf` asks for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quantiles {
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+/// The default quantile cut points reported for numeric columns.
+const DEFAULT_QUANTILES: [f64; 4] = [0.25, 0.5, 0.75, 0.95];
+
+/// The default number of most-frequent values kept in `top_values`.
+const DEFAULT_TOP_K: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Detailed profiling information for a single column (or, for `Struct` columns, a
+/// single leaf field reached via a dotted `parent.child` name).
 pub struct ColumnProfileDetailed {
     pub column: String,
     pub dtype: String,
@@ -83,7 +391,12 @@ pub struct ColumnProfileDetailed {
     pub unique: Option<usize>,
     pub min: MinMaxValue,
     pub max: MinMaxValue,
+    pub mean: Option<f64>,
+    pub std: Option<f64>,
+    pub quantiles: Option<Quantiles>,
     pub sample_values: Option<Vec<String>>,
+    pub list_length: Option<ListLengthStats>,
+    pub top_values: Vec<(String, usize)>,
 }
 
 
@@ -103,96 +416,391 @@ pub fn profile_df(df: DataFrame) -> Result<(usize, Vec<Profile>)> {
     Ok((row_count, profiles))
 }
 
-/// Profiles a DataFrame with detailed information
+/// Profiles a single non-nested column (scalar dtype or list) under `name`.
+fn profile_leaf(s: &Series, name: String) -> ColumnProfileDetailed {
+    let dtype_obj = s.dtype();
+    let dtype = format!("{:?}", dtype_obj);
+    let nulls = s.null_count();
+    let unique = s.n_unique().ok();
+
+    let (min, max) = match dtype_obj {
+        DataType::Int64 | DataType::Int32 => s.i64()
+            .map(|ca| (
+                ca.min().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
+                ca.max().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
+            ))
+            .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
+
+        DataType::Float64 | DataType::Float32 => s.f64()
+            .map(|ca| (
+                ca.min().map(MinMaxValue::Float).unwrap_or(MinMaxValue::None),
+                ca.max().map(MinMaxValue::Float).unwrap_or(MinMaxValue::None),
+            ))
+            .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
+
+        DataType::Boolean => s.bool()
+            .map(|ca| (
+                ca.min().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
+                ca.max().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
+            ))
+            .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
+
+        DataType::Date => s.i32()
+            .map(|ca| (
+                ca.min().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
+                ca.max().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
+            ))
+            .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
+
+        DataType::Datetime(_, _) => s.i64()
+            .map(|ca| (
+                ca.min().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
+                ca.max().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
+            ))
+            .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
+
+        DataType::String => s.str()
+            .map(|ca| (
+                ca.min().map(|v| MinMaxValue::Str(v.to_string())).unwrap_or(MinMaxValue::None),
+                ca.max().map(|v| MinMaxValue::Str(v.to_string())).unwrap_or(MinMaxValue::None),
+            ))
+            .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
+
+        _ => (MinMaxValue::None, MinMaxValue::None),
+    };
+
+    let is_numeric = matches!(
+        dtype_obj,
+        DataType::Int64 | DataType::Int32 | DataType::Float64 | DataType::Float32
+    );
+    let mean = is_numeric.then(|| s.mean()).flatten();
+    let std = is_numeric.then(|| s.std(1)).flatten();
+    let quantiles = is_numeric
+        .then(|| {
+            let p = |q: f64| {
+                s.quantile_reduce(q, QuantileMethod::Linear)
+                    .ok()
+                    .and_then(|scalar| scalar.value().extract::<f64>())
+                    .unwrap_or(f64::NAN)
+            };
+            Quantiles {
+                p25: p(DEFAULT_QUANTILES[0]),
+                p50: p(DEFAULT_QUANTILES[1]),
+                p75: p(DEFAULT_QUANTILES[2]),
+                p95: p(DEFAULT_QUANTILES[3]),
+            }
+        });
+
+    let top_values = top_k_values(s, DEFAULT_TOP_K).unwrap_or_default();
+
+    let sample_values = if matches!(dtype_obj, DataType::String) {
+        Some(
+            (0..s.len())
+                .filter_map(|idx| s.get(idx).ok())
+                .filter_map(|val| match val {
+                    polars::prelude::AnyValue::String(v) => Some(v.to_string()),
+                    _ => None,
+                })
+                .take(3)
+                .collect::<Vec<_>>()
+        )
+    } else {
+        None
+    };
+
+    let list_length = if let DataType::List(inner) = dtype_obj {
+        s.list().ok().map(|ca| {
+            let lengths: Vec<usize> = ca
+                .into_iter()
+                .map(|opt_s| opt_s.map(|inner_s| inner_s.len()).unwrap_or(0))
+                .collect();
+            let min_len = lengths.iter().copied().min().unwrap_or(0);
+            let max_len = lengths.iter().copied().max().unwrap_or(0);
+            let avg_len = if lengths.is_empty() {
+                0.0
+            } else {
+                lengths.iter().sum::<usize>() as f64 / lengths.len() as f64
+            };
+            ListLengthStats {
+                element_dtype: format!("{:?}", inner.as_ref()),
+                min_len,
+                max_len,
+                avg_len,
+            }
+        })
+    } else {
+        None
+    };
+
+    ColumnProfileDetailed {
+        column: name,
+        dtype,
+        nulls,
+        unique,
+        min,
+        max,
+        mean,
+        std,
+        quantiles,
+        sample_values,
+        list_length,
+        top_values,
+    }
+}
+
+/// Reports the `k` most frequent values in `s` and their counts, via a lazy
+/// group/count/sort/limit query so it rides along the same engine as the rest of
+/// the profile rather than a separate hand-rolled counting pass.
+fn top_k_values(s: &Series, k: usize) -> Result<Vec<(String, usize)>> {
+    let name = s.name().clone();
+    let df = DataFrame::new(vec![s.clone().into_column()])?;
+    let counted = df
+        .lazy()
+        .group_by([col(name.clone())])
+        .agg([len().alias("__count")])
+        .sort(["__count"], SortMultipleOptions::default().with_order_descending(true))
+        .limit(k as IdxSize)
+        .collect()?;
+
+    let values = counted.column(name.as_str())?;
+    let counts = counted.column("__count")?;
+    Ok((0..counted.height())
+        .filter_map(|i| {
+            let value = values.get(i).ok()?;
+            if matches!(value, AnyValue::Null) {
+                return None;
+            }
+            let count = counts.get(i).ok()?.extract::<usize>()?;
+            Some((format!("{value}"), count))
+        })
+        .collect())
+}
+
+/// Like `top_k_values`, but runs as its own small lazy query over a single column of
+/// `lf` rather than an in-memory `Series`, so it can ride along `profile_lazy`'s
+/// streaming scan instead of requiring the full column to already be materialized.
+fn top_k_values_lazy(lf: LazyFrame, name: &str, k: usize) -> Result<Vec<(String, usize)>> {
+    let counted = lf
+        .select([col(name)])
+        .group_by([col(name)])
+        .agg([len().alias("__count")])
+        .sort(["__count"], SortMultipleOptions::default().with_order_descending(true))
+        .limit(k as IdxSize)
+        .collect()?;
+
+    let values = counted.column(name)?;
+    let counts = counted.column("__count")?;
+    Ok((0..counted.height())
+        .filter_map(|i| {
+            let value = values.get(i).ok()?;
+            if matches!(value, AnyValue::Null) {
+                return None;
+            }
+            let count = counts.get(i).ok()?.extract::<usize>()?;
+            Some((format!("{value}"), count))
+        })
+        .collect())
+}
+
+/// Profiles a column, recursively unnesting `Struct` columns into one
+/// `ColumnProfileDetailed` per leaf field under a dotted `parent.child` name.
+fn profile_column_recursive(s: &Series, name: String) -> Vec<ColumnProfileDetailed> {
+    if let DataType::Struct(_) = s.dtype() {
+        match s.struct_() {
+            Ok(st) => st
+                .fields_as_series()
+                .iter()
+                .flat_map(|field_s| {
+                    profile_column_recursive(field_s, format!("{name}.{}", field_s.name()))
+                })
+                .collect(),
+            Err(_) => vec![profile_leaf(s, name)],
+        }
+    } else {
+        vec![profile_leaf(s, name)]
+    }
+}
+
+/// Profiles a DataFrame with detailed information. `Struct` columns are flattened
+/// into one entry per leaf field, and `List` columns report element-length
+/// statistics instead of a scalar min/max.
 pub fn profile_df_detailed(df: &DataFrame) -> Result<(usize, Vec<ColumnProfileDetailed>)> {
     let row_count = df.height();
-    let profiles: Vec<ColumnProfileDetailed> = df.get_columns()
-    .par_iter()
-    .map(|col| {
-        let s = col.as_series().expect("Expected a Series from Column"); // <-- THIS fixes type mismatch
-        
-        let dtype_obj = s.dtype();
-        let dtype = format!("{:?}", dtype_obj);
-        let nulls = s.null_count();
-        let unique = s.n_unique().ok();
-
-        let (min, max) = match dtype_obj {
-            DataType::Int64 | DataType::Int32 => s.i64()
-                .map(|ca| (
-                    ca.min().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
-                    ca.max().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
-                ))
-                .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
-
-            DataType::Float64 | DataType::Float32 => s.f64()
-                .map(|ca| (
-                    ca.min().map(MinMaxValue::Float).unwrap_or(MinMaxValue::None),
-                    ca.max().map(MinMaxValue::Float).unwrap_or(MinMaxValue::None),
-                ))
-                .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
-
-            DataType::Boolean => s.bool()
-                .map(|ca| (
-                    ca.min().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
-                    ca.max().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
-                ))
-                .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
-
-            DataType::Date => s.i32()
-                .map(|ca| (
-                    ca.min().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
-                    ca.max().map(|v| MinMaxValue::Int(v as i64)).unwrap_or(MinMaxValue::None),
-                ))
-                .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
-        
-            DataType::Datetime(_, _) => s.i64()
-                .map(|ca| (
-                    ca.min().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
-                    ca.max().map(MinMaxValue::Int).unwrap_or(MinMaxValue::None),
-                ))
-                .unwrap_or((MinMaxValue::None, MinMaxValue::None)),
-
-            DataType::String => (MinMaxValue::None, MinMaxValue::None),  // skip min/max for string
-
-            _ => (MinMaxValue::None, MinMaxValue::None),
-        };
+    let profiles: Vec<ColumnProfileDetailed> = df
+        .get_columns()
+        .par_iter()
+        .flat_map(|col| {
+            let s = col.as_series().expect("Expected a Series from Column"); // <-- THIS fixes type mismatch
+            profile_column_recursive(s, s.name().to_string())
+        })
+        .collect();
 
-        let sample_values = if matches!(dtype_obj, DataType::String) {
-            Some(
-                (0..s.len())
-                    .filter_map(|idx| s.get(idx).ok())
-                    .filter_map(|val| match val {
-                        polars::prelude::AnyValue::String(v) => Some(v.to_string()),
-                        _ => None,
-                    })
-                    .take(3)
-                    .collect::<Vec<_>>()
-            )
-        } else {
-            None
-        };
+    Ok((row_count, profiles))
+}
 
-        ColumnProfileDetailed {
-            column: s.name().to_string(),
-            dtype,
-            nulls,
-            unique,
-            min,
-            max,
-            sample_values,
+
+/// Maps a one-row lazy aggregate back into a `MinMaxValue`, following the same
+/// dtype dispatch as `profile_df_detailed`.
+fn any_value_to_minmax(value: AnyValue, dtype_obj: &DataType) -> MinMaxValue {
+    match dtype_obj {
+        DataType::Int64 | DataType::Int32 | DataType::Date | DataType::Datetime(_, _) => value
+            .extract::<i64>()
+            .map(MinMaxValue::Int)
+            .unwrap_or(MinMaxValue::None),
+        DataType::Float64 | DataType::Float32 => value
+            .extract::<f64>()
+            .map(MinMaxValue::Float)
+            .unwrap_or(MinMaxValue::None),
+        DataType::Boolean => match value {
+            AnyValue::Boolean(v) => MinMaxValue::Int(v as i64),
+            _ => MinMaxValue::None,
+        },
+        DataType::String => match value {
+            AnyValue::String(v) => MinMaxValue::Str(v.to_string()),
+            _ => MinMaxValue::None,
+        },
+        _ => MinMaxValue::None,
+    }
+}
+
+/// Profiles a file out-of-core using Polars' lazy engine and streaming collect,
+/// so files far larger than RAM can be profiled without `read_dataframe` ever
+/// materializing the whole thing into a `DataFrame`.
+pub fn profile_lazy(
+    path: &Path,
+    format: &InputFormat,
+    delimiter: u8,
+) -> Result<(usize, Vec<ColumnProfileDetailed>)> {
+    let lf = match format {
+        InputFormat::Csv | InputFormat::Txt => LazyCsvReader::new(path)
+            .with_has_header(true)
+            .with_separator(delimiter)
+            .finish()
+            .context("Failed to scan CSV/TXT file")?,
+        InputFormat::Json => LazyJsonLineReader::new(path)
+            .finish()
+            .context("Failed to scan JSON file")?,
+        InputFormat::Ndjson => LazyJsonLineReader::new(path)
+            .finish()
+            .context("Failed to scan NDJSON file")?,
+        InputFormat::Parquet => LazyFrame::scan_parquet(path, ScanArgsParquet::default())
+            .context("Failed to scan Parquet file")?,
+        InputFormat::Arrow => LazyFrame::scan_ipc(path, ScanArgsIpc::default())
+            .context("Failed to scan Arrow IPC file")?,
+        InputFormat::Unknown => return Err(anyhow::anyhow!("Unsupported file format")),
+    };
+
+    let schema = lf
+        .clone()
+        .collect_schema()
+        .context("Failed to resolve schema")?;
+
+    let mut aggs: Vec<Expr> = vec![len().alias("__row_count")];
+    for field in schema.iter_fields() {
+        let name = field.name().as_str();
+        let dtype = field.dtype();
+        aggs.push(col(name).null_count().alias(format!("{name}__nulls")));
+        aggs.push(col(name).min().alias(format!("{name}__min")));
+        aggs.push(col(name).max().alias(format!("{name}__max")));
+        aggs.push(col(name).n_unique().alias(format!("{name}__unique")));
+        if dtype.is_numeric() {
+            aggs.push(col(name).mean().alias(format!("{name}__mean")));
+            aggs.push(col(name).std(1).alias(format!("{name}__std")));
+            for q in DEFAULT_QUANTILES {
+                aggs.push(
+                    col(name)
+                        .quantile(lit(q), QuantileMethod::Linear)
+                        .alias(format!("{name}__p{}", (q * 100.0) as u32)),
+                );
+            }
         }
-    })
-    .collect();
+    }
+
+    let agg_df = lf
+        .clone()
+        .select(aggs)
+        .with_streaming(true)
+        .collect()
+        .context("Failed to evaluate lazy profiling query")?;
+
+    let row_count = agg_df
+        .column("__row_count")?
+        .get(0)?
+        .extract::<usize>()
+        .unwrap_or(0);
+
+    let profiles = schema
+        .iter_fields()
+        .map(|field| {
+            let name = field.name().as_str();
+            let dtype_obj = field.dtype();
+            let nulls = agg_df
+                .column(&format!("{name}__nulls"))?
+                .get(0)?
+                .extract::<usize>()
+                .unwrap_or(0);
+            let unique = agg_df
+                .column(&format!("{name}__unique"))?
+                .get(0)?
+                .extract::<usize>();
+            let min = any_value_to_minmax(agg_df.column(&format!("{name}__min"))?.get(0)?, dtype_obj);
+            let max = any_value_to_minmax(agg_df.column(&format!("{name}__max"))?.get(0)?, dtype_obj);
+
+            let (mean, std, quantiles) = if dtype_obj.is_numeric() {
+                let mean = agg_df
+                    .column(&format!("{name}__mean"))?
+                    .get(0)?
+                    .extract::<f64>();
+                let std = agg_df
+                    .column(&format!("{name}__std"))?
+                    .get(0)?
+                    .extract::<f64>();
+                let q = |p: f64| -> Result<f64> {
+                    Ok(agg_df
+                        .column(&format!("{name}__p{}", (p * 100.0) as u32))?
+                        .get(0)?
+                        .extract::<f64>()
+                        .unwrap_or(f64::NAN))
+                };
+                let quantiles = Quantiles {
+                    p25: q(DEFAULT_QUANTILES[0])?,
+                    p50: q(DEFAULT_QUANTILES[1])?,
+                    p75: q(DEFAULT_QUANTILES[2])?,
+                    p95: q(DEFAULT_QUANTILES[3])?,
+                };
+                (mean, std, Some(quantiles))
+            } else {
+                (None, None, None)
+            };
+
+            let top_values = top_k_values_lazy(lf.clone(), name, DEFAULT_TOP_K)
+                .unwrap_or_default();
+
+            Ok(ColumnProfileDetailed {
+                column: name.to_string(),
+                dtype: format!("{:?}", dtype_obj),
+                nulls,
+                unique,
+                min,
+                max,
+                mean,
+                std,
+                quantiles,
+                sample_values: None,
+                list_length: None,
+                top_values,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     Ok((row_count, profiles))
 }
 
-
-/// Profile any supported format file
-#[allow(dead_code)]
-pub fn profile_any(path: &Path, delimiter: u8) -> Result<(usize, Vec<Profile>)> {
+/// Profile any supported format file. `csv_opts` governs CSV/TXT parsing (nulls,
+/// comments, quoting, encoding, schema); it's ignored for other formats.
+pub fn profile_any(path: &Path, csv_opts: &CsvOptions) -> Result<(usize, Vec<Profile>)> {
     let format = infer_format(path);
-    let df = read_dataframe(path, &format, delimiter)?;
+    let df = read_dataframe(path, &format, csv_opts)?;
     profile_df(df)
 }
 
@@ -214,9 +822,9 @@ mod tests {
         let json_path = Path::new("components/data-profiler/data/examples/sample.json");
         let parquet_path = Path::new("components/data-profiler/data/examples/sample.parquet");
 
-        let (csv_rows, _) = profile_any(csv_path, b',').expect("CSV profiling failed");
-        let (json_rows, _) = profile_any(json_path, b',').expect("JSON profiling failed");
-        let (parquet_rows, _) = profile_any(parquet_path, b',').expect("Parquet profiling failed");
+        let (csv_rows, _) = profile_any(csv_path, &CsvOptions::default()).expect("CSV profiling failed");
+        let (json_rows, _) = profile_any(json_path, &CsvOptions::default()).expect("JSON profiling failed");
+        let (parquet_rows, _) = profile_any(parquet_path, &CsvOptions::default()).expect("Parquet profiling failed");
 
         println!("CSV: {csv_rows} rows, JSON: {json_rows} rows, Parquet: {parquet_rows} rows");
 
@@ -224,15 +832,163 @@ mod tests {
         assert!(json_rows > 0);
         assert!(parquet_rows > 0);
     }
+
+    #[test]
+    fn test_write_dataframe_csv_round_trip_eager_and_lazy() {
+        let df = df! { "a" => &[1i64, 2, 3], "b" => &["x", "y", "z"] }.unwrap();
+
+        let eager_path = Path::new("components/data-profiler/data/examples/write_roundtrip_eager.csv");
+        std::fs::create_dir_all(eager_path.parent().unwrap()).unwrap();
+        write_dataframe(FrameSource::Eager(df.clone()), eager_path, &WriteOptions::default())
+            .expect("failed to write CSV eagerly");
+        let read_back = read_dataframe(eager_path, &InputFormat::Csv, &CsvOptions::default()).expect("failed to read CSV back");
+        assert_eq!(read_back.height(), 3);
+        std::fs::remove_file(eager_path).unwrap();
+
+        let lazy_path = Path::new("components/data-profiler/data/examples/write_roundtrip_lazy.csv");
+        write_dataframe(FrameSource::Lazy(df.lazy()), lazy_path, &WriteOptions::default())
+            .expect("failed to sink CSV lazily");
+        let read_back = read_dataframe(lazy_path, &InputFormat::Csv, &CsvOptions::default()).expect("failed to read sunk CSV back");
+        assert_eq!(read_back.height(), 3);
+        std::fs::remove_file(lazy_path).unwrap();
+    }
+
+    #[test]
+    fn test_write_dataframe_ndjson_round_trip() {
+        let df = df! { "a" => &[1i64, 2, 3] }.unwrap();
+        let path = Path::new("components/data-profiler/data/examples/write_roundtrip.ndjson");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        write_dataframe(FrameSource::Eager(df), path, &WriteOptions::default()).expect("failed to write NDJSON");
+        let read_back = read_ndjson_dataframe(path, 1 << 16, None).expect("failed to read NDJSON back");
+        assert_eq!(read_back.height(), 3);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_write_dataframe_parquet_round_trip_eager_and_lazy() {
+        let df = df! { "a" => &[1i64, 2, 3] }.unwrap();
+
+        let eager_path = Path::new("components/data-profiler/data/examples/write_roundtrip_eager.parquet");
+        std::fs::create_dir_all(eager_path.parent().unwrap()).unwrap();
+        write_dataframe(FrameSource::Eager(df.clone()), eager_path, &WriteOptions::default())
+            .expect("failed to write Parquet eagerly");
+        let read_back = read_dataframe(eager_path, &InputFormat::Parquet, &CsvOptions::default()).expect("failed to read Parquet back");
+        assert_eq!(read_back.height(), 3);
+        std::fs::remove_file(eager_path).unwrap();
+
+        let lazy_path = Path::new("components/data-profiler/data/examples/write_roundtrip_lazy.parquet");
+        write_dataframe(FrameSource::Lazy(df.lazy()), lazy_path, &WriteOptions::default())
+            .expect("failed to sink Parquet lazily");
+        let read_back = read_dataframe(lazy_path, &InputFormat::Parquet, &CsvOptions::default()).expect("failed to read sunk Parquet back");
+        assert_eq!(read_back.height(), 3);
+        std::fs::remove_file(lazy_path).unwrap();
+    }
+
+    #[test]
+    fn test_write_dataframe_arrow_round_trip_eager_and_lazy() {
+        let df = df! { "a" => &[1i64, 2, 3] }.unwrap();
+
+        let eager_path = Path::new("components/data-profiler/data/examples/write_roundtrip_eager.arrow");
+        std::fs::create_dir_all(eager_path.parent().unwrap()).unwrap();
+        write_dataframe(FrameSource::Eager(df.clone()), eager_path, &WriteOptions::default())
+            .expect("failed to write Arrow IPC eagerly");
+        let read_back = read_dataframe(eager_path, &InputFormat::Arrow, &CsvOptions::default()).expect("failed to read Arrow IPC back");
+        assert_eq!(read_back.height(), 3);
+        std::fs::remove_file(eager_path).unwrap();
+
+        let lazy_path = Path::new("components/data-profiler/data/examples/write_roundtrip_lazy.arrow");
+        write_dataframe(FrameSource::Lazy(df.lazy()), lazy_path, &WriteOptions::default())
+            .expect("failed to sink Arrow IPC lazily");
+        let read_back = read_dataframe(lazy_path, &InputFormat::Arrow, &CsvOptions::default()).expect("failed to read sunk Arrow IPC back");
+        assert_eq!(read_back.height(), 3);
+        std::fs::remove_file(lazy_path).unwrap();
+    }
+
+    #[test]
+    fn test_read_dataframe_csv_options_null_values_and_comment_prefix() {
+        let path = Path::new("components/data-profiler/data/examples/csv_options_test.csv");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "# a comment line\nname,age\nalice,30\nbob,NA\n").unwrap();
+
+        let opts = CsvOptions {
+            null_values: vec!["NA".to_string()],
+            comment_prefix: Some("#".to_string()),
+            ..CsvOptions::default()
+        };
+        let df = read_dataframe(path, &InputFormat::Csv, &opts).expect("failed to read CSV with options");
+
+        assert_eq!(df.height(), 2, "comment line should be skipped, not read as a row");
+        assert_eq!(
+            df.column("age").unwrap().null_count(),
+            1,
+            "NA should be parsed as a null, not the string \"NA\""
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_sniffs_as_ndjson_detects_object_stream() {
+        let path = Path::new("components/data-profiler/data/examples/sniff_object_stream.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "{\"a\": 1}\n{\"a\": 2}\n").unwrap();
+
+        assert!(sniffs_as_ndjson(path));
+        assert!(matches!(infer_format(path), InputFormat::Ndjson));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_sniffs_as_ndjson_false_for_single_object() {
+        let path = Path::new("components/data-profiler/data/examples/sniff_single_object.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "{\"a\": 1, \"nested\": {\"b\": 2}}").unwrap();
+
+        assert!(!sniffs_as_ndjson(path));
+        assert!(matches!(infer_format(path), InputFormat::Json));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_sniffs_as_ndjson_false_for_array() {
+        let path = Path::new("components/data-profiler/data/examples/sniff_array.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "[{\"a\": 1}, {\"a\": 2}]").unwrap();
+
+        assert!(!sniffs_as_ndjson(path));
+        assert!(matches!(infer_format(path), InputFormat::Json));
+
+        std::fs::remove_file(path).unwrap();
+    }
 }
 
 
 #[test]
 fn test_profile_detailed() {
     let path = Path::new("components/data-profiler/data/examples/sample.csv");
-    let df = read_dataframe(path, &infer_format(path), b',').expect("failed to read");
+    let df = read_dataframe(path, &infer_format(path), &CsvOptions::default()).expect("failed to read");
     let (_, profiles) = profile_df_detailed(&df).expect("detailed profiling failed");
 
     assert!(!profiles.is_empty());
     println!("{:#?}", profiles[0]);
+}
+
+#[test]
+fn test_profile_lazy_matches_eager() {
+    let path = Path::new("components/data-profiler/data/examples/sample.csv");
+    let format = infer_format(path);
+
+    let (eager_rows, eager_profiles) = {
+        let df = read_dataframe(path, &format, &CsvOptions::default()).expect("failed to read");
+        profile_df_detailed(&df).expect("detailed profiling failed")
+    };
+    let (lazy_rows, lazy_profiles) =
+        profile_lazy(path, &format, b',').expect("lazy profiling failed");
+
+    assert_eq!(eager_rows, lazy_rows);
+    assert_eq!(eager_profiles.len(), lazy_profiles.len());
 }
\ No newline at end of file
@@ -5,4 +5,31 @@ use serde::Deserialize;
 #[derive(Deserialize, Debug)]
 pub struct DbConfig {
     pub path: String,
+
+    #[serde(default)]
+    pub connect_retry: ConnectRetryConfig,
+}
+
+/// Timing knobs for `connect()`'s retry loop. All fields are optional in config
+/// files/env-sourced JSON; defaults match the backoff described on
+/// `connect_with_retry` (~100ms start, doubling up to a 30s cap, give up after ~60s).
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ConnectRetryConfig {
+    pub initial_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub max_elapsed_ms: u64,
+    /// Set to skip the retry loop entirely and fail on the first error, as before.
+    pub disabled: bool,
+}
+
+impl Default for ConnectRetryConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 100,
+            max_delay_ms: 30_000,
+            max_elapsed_ms: 60_000,
+            disabled: false,
+        }
+    }
 }
@@ -1,19 +1,23 @@
 //pub mod config;: Makes the config module (with your DbConfig struct) available.
 pub mod config;
+pub mod migrations;
+pub mod sql_io;
+pub use migrations::migrate;
+pub use sql_io::{extract_sql, load_sql, SqlLoadMode};
 //Imports: Brings in error handling (anyhow::Result), your config struct, and the necessary types from sqlx for SQLite connection pooling.
 use anyhow::Result;
-use config::DbConfig;
+use config::{ConnectRetryConfig, DbConfig};
+use rand::Rng;
 use sqlx::{sqlite::SqliteConnectOptions, sqlite::SqlitePoolOptions, SqlitePool};
+use std::io::ErrorKind;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 //Purpose: Opens an async SQLite connection pool to the file at cfg.path.
 pub async fn connect(cfg: &DbConfig) -> Result<SqlitePool> {
     let options = SqliteConnectOptions::from_str(&cfg.path)? //Parses the path from your config into SQLite connection options.
         .create_if_missing(true); //database file is created if it doesn’t exist.
-    let pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect_with(options)
-        .await?; //Creates a connection pool with up to 5 connections.
+    let pool = connect_with_retry(options, &cfg.connect_retry).await?; //Creates a connection pool with up to 5 connections, retrying transient I/O failures.
                  // Set PRAGMA foreign_keys = ON for every connection
     sqlx::query("PRAGMA foreign_keys = ON;") //to enforce foreign key constraints on every connection. Enabling this ensures that your database will respect foreign key relationships (e.g., prevent deleting a parent row if child rows exist), which is important for data integrity.
         .execute(&pool)
@@ -21,6 +25,52 @@ pub async fn connect(cfg: &DbConfig) -> Result<SqlitePool> {
     Ok(pool) //Returns the pool or an error.
 }
 
+/// Retries `connect_with` on transient I/O errors (connection refused/reset/aborted —
+/// e.g. the database is still starting up) with exponential backoff and jitter,
+/// doubling the delay each attempt up to `max_delay_ms` and giving up once
+/// `max_elapsed_ms` has passed since the first attempt. Any other error (including a
+/// bad path or malformed SQLite file) is treated as permanent and returned immediately.
+/// Setting `retry.disabled` skips the loop and behaves like a single bare attempt.
+async fn connect_with_retry(options: SqliteConnectOptions, retry: &ConnectRetryConfig) -> Result<SqlitePool> {
+    if retry.disabled {
+        return Ok(SqlitePoolOptions::new().max_connections(5).connect_with(options).await?);
+    }
+
+    let start = Instant::now();
+    let mut delay_ms = retry.initial_delay_ms;
+    loop {
+        match SqlitePoolOptions::new().max_connections(5).connect_with(options.clone()).await {
+            Ok(pool) => return Ok(pool),
+            Err(err) if is_transient_io_error(&err) && start.elapsed() < Duration::from_millis(retry.max_elapsed_ms) => {
+                let jitter_ms = rand::thread_rng().gen_range(0..=delay_ms / 4 + 1);
+                tokio::time::sleep(Duration::from_millis(delay_ms + jitter_ms)).await;
+                delay_ms = (delay_ms * 2).min(retry.max_delay_ms);
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn is_transient_io_error(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::Io(io_err) => matches!(
+            io_err.kind(),
+            ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted
+        ),
+        _ => false,
+    }
+}
+
+/// Opens a pool via `connect()` and brings its schema up to date via
+/// [`migrate`] before returning it, so callers (like `load_sql`) can rely on
+/// the target schema already existing instead of issuing their own ad-hoc
+/// `CREATE TABLE` calls.
+pub async fn connect_and_migrate(cfg: &DbConfig) -> Result<SqlitePool> {
+    let pool = connect(cfg).await?;
+    migrate(&pool).await?;
+    Ok(pool)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,4 +84,33 @@ mod tests {
         let row: (i32,) = sqlx::query_as("SELECT 1").fetch_one(&pool).await.unwrap();
         assert_eq!(row.0, 1);
     }
+
+    #[test]
+    fn is_transient_io_error_only_matches_known_transient_kinds() {
+        let transient = sqlx::Error::Io(std::io::Error::from(ErrorKind::ConnectionReset));
+        assert!(is_transient_io_error(&transient));
+
+        let permanent = sqlx::Error::Io(std::io::Error::from(ErrorKind::PermissionDenied));
+        assert!(!is_transient_io_error(&permanent));
+
+        let non_io = sqlx::Error::RowNotFound;
+        assert!(!is_transient_io_error(&non_io));
+    }
+
+    #[tokio::test]
+    async fn connect_with_retry_disabled_behaves_like_single_attempt() {
+        let cfg: DbConfig = serde_json::from_str(r#"{ "path": ":memory:", "connect_retry": { "disabled": true } }"#).unwrap();
+        let pool = connect(&cfg).await.expect("Should connect to SQLite with retries disabled");
+        let row: (i32,) = sqlx::query_as("SELECT 1").fetch_one(&pool).await.unwrap();
+        assert_eq!(row.0, 1);
+    }
+
+    #[tokio::test]
+    async fn connect_and_migrate_leaves_migrations_table_populated() {
+        let json = r#"{ "path": ":memory:" }"#;
+        let cfg: DbConfig = serde_json::from_str(json).unwrap();
+        let pool = connect_and_migrate(&cfg).await.expect("Should connect and migrate");
+        let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM migrations").fetch_one(&pool).await.unwrap();
+        assert!(count.0 > 0);
+    }
 }
@@ -0,0 +1,84 @@
+//! Embedded SQL migrations: every `.sql` file under `migrations/` is baked into
+//! the binary at compile time via `include_dir!`, so a database gets the right
+//! schema without shipping the `.sql` files alongside it or relying on ad-hoc
+//! `CREATE TABLE` calls scattered through the pipeline.
+
+use anyhow::Result;
+use include_dir::{include_dir, Dir};
+use sqlx::SqlitePool;
+
+static MIGRATIONS_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/migrations");
+
+/// Applies any `migrations/*.sql` file not yet recorded in the `migrations`
+/// table, in filename order (hence the `NNNN_description.sql` naming), each
+/// inside its own transaction. Files already recorded are skipped, so this is
+/// safe to call on every `connect()` (see `connect_and_migrate`).
+pub async fn migrate(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS migrations (
+            version TEXT PRIMARY KEY,
+            applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )",
+    )
+    .execute(pool)
+    .await?;
+
+    let mut files: Vec<_> = MIGRATIONS_DIR.files().collect();
+    files.sort_by_key(|f| f.path().to_path_buf());
+
+    for file in files {
+        let version = file
+            .path()
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| anyhow::anyhow!("Migration path {:?} has no file name", file.path()))?
+            .to_string();
+
+        let already_applied: Option<(String,)> = sqlx::query_as("SELECT version FROM migrations WHERE version = ?")
+            .bind(&version)
+            .fetch_optional(pool)
+            .await?;
+        if already_applied.is_some() {
+            continue;
+        }
+
+        let sql = file
+            .contents_utf8()
+            .ok_or_else(|| anyhow::anyhow!("Migration {} is not valid UTF-8", version))?;
+
+        let mut tx = pool.begin().await?;
+        for statement in sql.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            sqlx::query(statement).execute(&mut *tx).await?;
+        }
+        sqlx::query("INSERT INTO migrations (version) VALUES (?)")
+            .bind(&version)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DbConfig;
+
+    async fn memory_pool() -> SqlitePool {
+        let cfg: DbConfig = serde_json::from_str(r#"{ "path": ":memory:" }"#).unwrap();
+        crate::connect(&cfg).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn migrate_is_idempotent() {
+        let pool = memory_pool().await;
+        migrate(&pool).await.unwrap();
+        migrate(&pool).await.unwrap();
+
+        let applied: Vec<(String,)> = sqlx::query_as("SELECT version FROM migrations")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(applied.len(), MIGRATIONS_DIR.files().count());
+    }
+}
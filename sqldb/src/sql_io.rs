@@ -0,0 +1,199 @@
+//! SQLite as a first-class extract source and load sink: `extract_sql` runs a
+//! `SELECT` into a Polars `DataFrame`, and `load_sql` writes one back out, creating
+//! the target table from the DataFrame's schema if it doesn't exist yet.
+
+use anyhow::Result;
+use polars::prelude::*;
+use sqlx::{sqlite::SqliteArguments, Column, Row, Sqlite, SqlitePool, TypeInfo};
+
+/// How `load_sql` treats a table that already exists.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SqlLoadMode {
+    /// Keep the existing table and its rows, inserting the DataFrame's rows on top.
+    Append,
+    /// Drop the existing table (if any) before recreating and inserting.
+    Replace,
+}
+
+/// Rows per `INSERT` transaction in `load_sql`. Keeps a single large DataFrame
+/// from holding one transaction (and its WAL growth) open for the entire load.
+const SQL_LOAD_BATCH_SIZE: usize = 500;
+
+/// Runs an arbitrary `SELECT` and builds a `DataFrame` column-by-column from the
+/// returned rows, mapping each column's SQLite storage class (`INTEGER`/`REAL`/
+/// `TEXT`/`BLOB`) to an `Int64`/`Float64`/`String`/`Binary` series. An empty result
+/// set (zero rows) returns an empty `DataFrame` rather than erroring, since there's
+/// no column metadata to build a schema from without a row to inspect.
+pub async fn extract_sql(pool: &SqlitePool, query: &str) -> Result<DataFrame> {
+    let rows = sqlx::query(query).fetch_all(pool).await?;
+    let Some(first_row) = rows.first() else {
+        return Ok(DataFrame::default());
+    };
+
+    let columns = first_row.columns();
+    let mut series = Vec::with_capacity(columns.len());
+    for (i, column) in columns.iter().enumerate() {
+        let name = column.name();
+        let values: Column = match column.type_info().name() {
+            "INTEGER" => {
+                let values: Vec<Option<i64>> =
+                    rows.iter().map(|r| r.try_get::<Option<i64>, _>(i)).collect::<std::result::Result<_, _>>()?;
+                Series::new(name.into(), values).into()
+            }
+            "REAL" => {
+                let values: Vec<Option<f64>> =
+                    rows.iter().map(|r| r.try_get::<Option<f64>, _>(i)).collect::<std::result::Result<_, _>>()?;
+                Series::new(name.into(), values).into()
+            }
+            "BLOB" => {
+                let values: Vec<Option<Vec<u8>>> =
+                    rows.iter().map(|r| r.try_get::<Option<Vec<u8>>, _>(i)).collect::<std::result::Result<_, _>>()?;
+                Series::new(name.into(), values).into()
+            }
+            // TEXT, NULL, and anything else SQLite's dynamic typing hands back.
+            _ => {
+                let values: Vec<Option<String>> =
+                    rows.iter().map(|r| r.try_get::<Option<String>, _>(i)).collect::<std::result::Result<_, _>>()?;
+                Series::new(name.into(), values).into()
+            }
+        };
+        series.push(values);
+    }
+    DataFrame::new(series).map_err(Into::into)
+}
+
+fn sqlite_type_for(dtype: &DataType) -> &'static str {
+    match dtype {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => "INTEGER",
+        DataType::Float32 | DataType::Float64 => "REAL",
+        DataType::Binary => "BLOB",
+        _ => "TEXT",
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+fn create_table_sql(table: &str, df: &DataFrame) -> String {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|c| format!("{} {}", quote_ident(c.name()), sqlite_type_for(c.dtype())))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("CREATE TABLE IF NOT EXISTS {} ({})", quote_ident(table), columns)
+}
+
+fn bind_any_value<'q>(
+    query: sqlx::query::Query<'q, Sqlite, SqliteArguments<'q>>,
+    value: AnyValue<'q>,
+) -> sqlx::query::Query<'q, Sqlite, SqliteArguments<'q>> {
+    match value {
+        AnyValue::Null => query.bind(None::<i64>),
+        AnyValue::Boolean(v) => query.bind(v as i64),
+        AnyValue::Int8(v) => query.bind(v as i64),
+        AnyValue::Int16(v) => query.bind(v as i64),
+        AnyValue::Int32(v) => query.bind(v as i64),
+        AnyValue::Int64(v) => query.bind(v),
+        AnyValue::UInt8(v) => query.bind(v as i64),
+        AnyValue::UInt16(v) => query.bind(v as i64),
+        AnyValue::UInt32(v) => query.bind(v as i64),
+        AnyValue::UInt64(v) => query.bind(v as i64),
+        AnyValue::Float32(v) => query.bind(v as f64),
+        AnyValue::Float64(v) => query.bind(v),
+        AnyValue::String(v) => query.bind(v.to_string()),
+        other => query.bind(other.to_string()),
+    }
+}
+
+/// Creates `table` from `df`'s schema if it's missing (or drops and recreates it
+/// first when `mode` is `Replace`), then inserts `df`'s rows in batches of
+/// `SQL_LOAD_BATCH_SIZE`, each its own committed transaction.
+pub async fn load_sql(pool: &SqlitePool, df: &DataFrame, table: &str, mode: SqlLoadMode) -> Result<()> {
+    if mode == SqlLoadMode::Replace {
+        sqlx::query(&format!("DROP TABLE IF EXISTS {}", quote_ident(table))).execute(pool).await?;
+    }
+    sqlx::query(&create_table_sql(table, df)).execute(pool).await?;
+
+    let column_names = df.get_column_names();
+    let insert_stmt = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        quote_ident(table),
+        column_names.iter().map(|c| quote_ident(c)).collect::<Vec<_>>().join(", "),
+        column_names.iter().map(|_| "?").collect::<Vec<_>>().join(", "),
+    );
+
+    let height = df.height();
+    let mut start = 0;
+    while start < height {
+        let end = (start + SQL_LOAD_BATCH_SIZE).min(height);
+        let mut tx = pool.begin().await?;
+        for row_idx in start..end {
+            let mut query = sqlx::query(&insert_stmt);
+            for column in df.get_columns() {
+                query = bind_any_value(query, column.get(row_idx)?);
+            }
+            query.execute(&mut *tx).await?;
+        }
+        tx.commit().await?;
+        start = end;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DbConfig;
+
+    async fn memory_pool() -> SqlitePool {
+        let cfg: DbConfig = serde_json::from_str(r#"{ "path": ":memory:" }"#).unwrap();
+        crate::connect(&cfg).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_load_sql_then_extract_sql_round_trips() {
+        let pool = memory_pool().await;
+        let df = df! {
+            "id" => &[1_i64, 2, 3],
+            "name" => &["alice", "bob", "carol"],
+            "score" => &[1.5_f64, 2.5, 3.5],
+        }
+        .unwrap();
+
+        load_sql(&pool, &df, "people", SqlLoadMode::Replace).await.unwrap();
+        let out = extract_sql(&pool, "SELECT id, name, score FROM people ORDER BY id").await.unwrap();
+
+        assert_eq!(out.height(), 3);
+        assert_eq!(out.column("name").unwrap().str().unwrap().get(1).unwrap(), "bob");
+    }
+
+    #[tokio::test]
+    async fn test_load_sql_append_adds_rows() {
+        let pool = memory_pool().await;
+        let df = df! { "id" => &[1_i64] }.unwrap();
+
+        load_sql(&pool, &df, "ids", SqlLoadMode::Replace).await.unwrap();
+        load_sql(&pool, &df, "ids", SqlLoadMode::Append).await.unwrap();
+
+        let out = extract_sql(&pool, "SELECT id FROM ids").await.unwrap();
+        assert_eq!(out.height(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_extract_sql_empty_result_returns_empty_dataframe() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE empty_table (id INTEGER)").execute(&pool).await.unwrap();
+        let out = extract_sql(&pool, "SELECT id FROM empty_table").await.unwrap();
+        assert_eq!(out.height(), 0);
+    }
+}